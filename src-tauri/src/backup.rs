@@ -0,0 +1,272 @@
+// JSON backup export/import
+//
+// The frontend hands over its data as a JSON string; the user picks where it
+// goes through a native file dialog. Encrypted backups derive an AES-256-GCM
+// key from the user's password with Argon2 and are laid out as
+// `MAGIC || salt || nonce || ciphertext`.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+use crate::recent_files;
+
+/// Error returned when the user dismisses the file dialog, so the frontend can
+/// tell it apart from a real failure.
+pub const CANCELLED: &str = "cancelled";
+
+/// Largest backup `import_data` will load into memory.
+const MAX_IMPORT_BYTES: u64 = 50 * 1024 * 1024;
+
+// Identifies an encrypted backup and its format version
+const MAGIC: &[u8] = b"RDENC1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const ENCRYPTED_EXTENSION: &str = "rdbackup";
+
+/// Writes `contents` to a temp file next to `path` and renames it into place,
+/// so a crash mid-write never leaves a truncated backup behind.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("invalid path: {}", path.display()))?;
+    let mut tmp_name = file_name.to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let result = File::create(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_path, path));
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e.to_string());
+    }
+    Ok(())
+}
+
+// Failing to update the recent list shouldn't fail the export/import itself
+fn remember(app: &AppHandle, path: &Path) {
+    if let Err(e) = recent_files::push(app, path) {
+        log::error!("Failed to update recent files: {}", e);
+    }
+}
+
+/// Asks the user for a destination and writes `data` there. Returns the path
+/// written to, or `CANCELLED` if the dialog was dismissed.
+#[tauri::command]
+pub async fn export_data(app: AppHandle, data: String) -> Result<String, String> {
+    let path = app
+        .dialog()
+        .file()
+        .add_filter("JSON", &["json"])
+        .set_file_name("richdad-backup.json")
+        .blocking_save_file()
+        .ok_or(CANCELLED)?
+        .into_path()
+        .map_err(|e| e.to_string())?;
+
+    write_atomic(&path, data.as_bytes())?;
+    remember(&app, &path);
+    Ok(path.display().to_string())
+}
+
+/// Asks the user for a backup file and returns its contents once they have
+/// been checked to be well-formed JSON. Returns `CANCELLED` if the dialog was
+/// dismissed.
+#[tauri::command]
+pub async fn import_data(app: AppHandle) -> Result<String, String> {
+    let path = app
+        .dialog()
+        .file()
+        .add_filter("JSON", &["json"])
+        .blocking_pick_file()
+        .ok_or(CANCELLED)?
+        .into_path()
+        .map_err(|e| e.to_string())?;
+
+    let contents = String::from_utf8(read_limited(&path)?)
+        .map_err(|_| "backup is not valid UTF-8".to_string())?;
+    check_json(&contents)?;
+
+    remember(&app, &path);
+    Ok(contents)
+}
+
+fn read_limited(path: &Path) -> Result<Vec<u8>, String> {
+    let size = fs::metadata(path).map_err(|e| e.to_string())?.len();
+    if size > MAX_IMPORT_BYTES {
+        return Err(format!(
+            "backup is too large ({} MB, limit is {} MB)",
+            size / (1024 * 1024),
+            MAX_IMPORT_BYTES / (1024 * 1024)
+        ));
+    }
+    fs::read(path).map_err(|e| e.to_string())
+}
+
+fn check_json(contents: &str) -> Result<(), String> {
+    serde_json::from_str::<serde_json::Value>(contents)
+        .map(|_| ())
+        .map_err(|e| format!("backup is not valid JSON: {}", e))
+}
+
+fn cipher_for(password: &str, salt: &[u8]) -> Result<Aes256Gcm, String> {
+    if password.is_empty() {
+        return Err("password must not be empty".into());
+    }
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("failed to derive key: {}", e))?;
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+}
+
+fn encrypt(data: &[u8], password: &str) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = cipher_for(password, &salt)?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, data)
+        .map_err(|_| "encryption failed".to_string())?;
+
+    let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn decrypt(contents: &[u8], password: &str) -> Result<Vec<u8>, String> {
+    let body = contents
+        .strip_prefix(MAGIC)
+        .filter(|body| body.len() > SALT_LEN + NONCE_LEN)
+        .ok_or("file is not an encrypted RichDad backup")?;
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    // AES-GCM can't tell a wrong key from a tampered file; a wrong password
+    // is by far the likelier cause
+    cipher_for(password, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "wrong password".to_string())
+}
+
+/// Like `export_data`, but encrypts `data` with a key derived from
+/// `password` before writing it.
+#[tauri::command]
+pub async fn export_data_encrypted(
+    app: AppHandle,
+    data: String,
+    password: String,
+) -> Result<String, String> {
+    let encrypted = encrypt(data.as_bytes(), &password)?;
+
+    let path = app
+        .dialog()
+        .file()
+        .add_filter("Encrypted backup", &[ENCRYPTED_EXTENSION])
+        .set_file_name(format!("richdad-backup.{}", ENCRYPTED_EXTENSION))
+        .blocking_save_file()
+        .ok_or(CANCELLED)?
+        .into_path()
+        .map_err(|e| e.to_string())?;
+
+    write_atomic(&path, &encrypted)?;
+    remember(&app, &path);
+    Ok(path.display().to_string())
+}
+
+/// Decrypts an encrypted backup and returns its JSON. Reads `path` when given
+/// (e.g. from the recent files list), otherwise asks the user for a file.
+/// Fails with "wrong password" if the file doesn't authenticate.
+#[tauri::command]
+pub async fn import_data_encrypted(
+    app: AppHandle,
+    path: Option<String>,
+    password: String,
+) -> Result<String, String> {
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => app
+            .dialog()
+            .file()
+            .add_filter("Encrypted backup", &[ENCRYPTED_EXTENSION])
+            .blocking_pick_file()
+            .ok_or(CANCELLED)?
+            .into_path()
+            .map_err(|e| e.to_string())?,
+    };
+
+    let decrypted = decrypt(&read_limited(&path)?, &password)?;
+    let contents =
+        String::from_utf8(decrypted).map_err(|_| "backup is not valid UTF-8".to_string())?;
+    check_json(&contents)?;
+
+    remember(&app, &path);
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &[u8] = br#"{"transactions":[]}"#;
+
+    #[test]
+    fn round_trip() {
+        let encrypted = encrypt(DATA, "hunter2").unwrap();
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(decrypt(&encrypted, "hunter2").unwrap(), DATA);
+    }
+
+    #[test]
+    fn wrong_password() {
+        let encrypted = encrypt(DATA, "hunter2").unwrap();
+        assert_eq!(
+            decrypt(&encrypted, "hunter3").unwrap_err(),
+            "wrong password"
+        );
+    }
+
+    #[test]
+    fn truncated_body() {
+        let encrypted = encrypt(DATA, "hunter2").unwrap();
+        let truncated = &encrypted[..MAGIC.len() + SALT_LEN + NONCE_LEN];
+        assert_eq!(
+            decrypt(truncated, "hunter2").unwrap_err(),
+            "file is not an encrypted RichDad backup"
+        );
+    }
+
+    #[test]
+    fn missing_magic() {
+        let encrypted = encrypt(DATA, "hunter2").unwrap();
+        assert_eq!(
+            decrypt(&encrypted[MAGIC.len()..], "hunter2").unwrap_err(),
+            "file is not an encrypted RichDad backup"
+        );
+    }
+
+    #[test]
+    fn empty_password() {
+        assert_eq!(encrypt(DATA, "").unwrap_err(), "password must not be empty");
+        let encrypted = encrypt(DATA, "hunter2").unwrap();
+        assert_eq!(
+            decrypt(&encrypted, "").unwrap_err(),
+            "password must not be empty"
+        );
+    }
+}
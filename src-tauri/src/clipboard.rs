@@ -0,0 +1,20 @@
+// Clipboard access
+//
+// Generated values (totals, tables) are copied from Rust rather than relying
+// on webview text selection.
+
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+#[tauri::command]
+pub fn copy_to_clipboard(app: AppHandle, text: String) -> Result<(), String> {
+    app.clipboard().write_text(text).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn read_clipboard(app: AppHandle) -> Result<String, String> {
+    // The clipboard backend reports non-text content (an image, copied files,
+    // an empty clipboard) as a read error; for pasting that just means there
+    // is no text
+    Ok(app.clipboard().read_text().unwrap_or_default())
+}
@@ -0,0 +1,133 @@
+// Close confirmation
+//
+// Closing a window is held back and a `close-requested` event is sent to its
+// frontend, which calls `confirm_close` once unsaved changes are dealt with or
+// `cancel_close` if the user chooses to stay.
+//
+// Closing the last visible window quits the app, so that one gets a
+// `confirm-quit` event instead and the frontend answers with `quit_app`.
+//
+// The frontend calls `acknowledge_close` as soon as it gets either event. If
+// that doesn't happen (e.g. it has hung), the window is destroyed anyway once
+// `CONFIRM_TIMEOUT` has passed, so a window can never become impossible to
+// close. An acknowledged request waits for its answer however long the user
+// takes.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{CloseRequestApi, Emitter, Manager, Window};
+
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Each window's unanswered close request
+struct Pending {
+    // Lets a fallback timer tell whether the request it was started for is
+    // still the one pending
+    id: u64,
+    acknowledged: bool,
+}
+
+static PENDING: Mutex<BTreeMap<String, Pending>> = Mutex::new(BTreeMap::new());
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+pub fn on_close_requested(window: &Window, api: &CloseRequestApi) {
+    let label = window.label();
+    api.prevent_close();
+
+    // Closing again while a request is pending asks again, in case the first
+    // event was missed, but keeps the original deadline
+    let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    if !pending.contains_key(label) {
+        let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+        pending.insert(
+            label.to_string(),
+            Pending {
+                id,
+                acknowledged: false,
+            },
+        );
+        close_after_timeout(window.clone(), id);
+    }
+    drop(pending);
+
+    let event = if is_last_window(window) {
+        "confirm-quit"
+    } else {
+        "close-requested"
+    };
+    if let Err(e) = window.emit_to(label, event, ()) {
+        log::error!("Failed to emit {}: {}", event, e);
+    }
+}
+
+/// Whether closing `window` leaves no other visible app window. Modals, the
+/// splash screen and windows hidden to the tray don't count.
+pub fn is_last_window(window: &Window) -> bool {
+    !window.app_handle().webview_windows().values().any(|other| {
+        other.label() != window.label()
+            && crate::is_app_window(other.label())
+            && other.is_visible().unwrap_or(false)
+    })
+}
+
+fn close_after_timeout(window: Window, id: u64) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(CONFIRM_TIMEOUT).await;
+
+        let label = window.label();
+        let still_pending = {
+            let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+            let still_pending = pending
+                .get(label)
+                .is_some_and(|request| request.id == id && !request.acknowledged);
+            if still_pending {
+                pending.remove(label);
+            }
+            still_pending
+        };
+        if !still_pending {
+            return;
+        }
+
+        log::warn!("No close confirmation from {}, closing anyway", label);
+        if let Err(e) = window.destroy() {
+            log::error!("Failed to close {} after timeout: {}", label, e);
+        }
+    });
+}
+
+pub fn forget(window: &Window) {
+    PENDING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(window.label());
+}
+
+/// Tells the guard the frontend has seen the close request and will answer it
+/// with `confirm_close`, `cancel_close` or `quit_app`, so the fallback timer
+/// stops.
+#[tauri::command]
+pub fn acknowledge_close(window: Window) {
+    if let Some(request) = PENDING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_mut(window.label())
+    {
+        request.acknowledged = true;
+    }
+}
+
+#[tauri::command]
+pub fn confirm_close(window: Window) -> Result<(), String> {
+    forget(&window);
+    // `destroy` skips CloseRequested, so this doesn't loop back into the guard
+    window.destroy().map_err(crate::window_error)
+}
+
+#[tauri::command]
+pub fn cancel_close(window: Window) {
+    forget(&window);
+}
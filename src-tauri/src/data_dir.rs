@@ -0,0 +1,31 @@
+// App data directory
+//
+// Where the database lives; surfaced to the frontend so users can find their
+// data and backups.
+
+use std::fs;
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+/// Resolves the app data dir, creating it if it doesn't exist yet.
+pub fn resolve(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+#[tauri::command]
+pub fn get_data_dir(app: AppHandle) -> Result<String, String> {
+    Ok(resolve(&app)?.display().to_string())
+}
+
+/// Opens the data dir in Finder/Explorer/the desktop file manager.
+#[tauri::command]
+pub fn open_data_dir(app: AppHandle) -> Result<(), String> {
+    let dir = resolve(&app)?;
+    app.opener()
+        .open_path(dir.display().to_string(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
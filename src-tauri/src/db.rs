@@ -0,0 +1,220 @@
+// SQLite storage
+//
+// A single connection lives in managed state behind a `Mutex`. If the
+// database can't be opened (corrupt file, locked by another process, ...) the
+// error is kept instead, and every command reports it rather than panicking.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rusqlite::{params, Connection, DatabaseName, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_dialog::DialogExt;
+
+use crate::backup::CANCELLED;
+use crate::data_dir;
+
+const DB_FILE: &str = "richdad.db";
+
+// Applied in order; a database's `user_version` records how many have run
+const MIGRATIONS: &[&str] = &[r#"
+    CREATE TABLE accounts (
+        id          INTEGER PRIMARY KEY,
+        name        TEXT NOT NULL UNIQUE,
+        kind        TEXT NOT NULL DEFAULT 'checking',
+        created_at  TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE TABLE transactions (
+        id            INTEGER PRIMARY KEY,
+        account_id    INTEGER REFERENCES accounts(id) ON DELETE CASCADE,
+        amount_cents  INTEGER NOT NULL,
+        description   TEXT NOT NULL DEFAULT '',
+        category      TEXT,
+        occurred_at   TEXT NOT NULL,
+        created_at    TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX idx_transactions_account ON transactions(account_id, occurred_at);
+"#];
+
+pub struct Db(Mutex<Result<Connection, String>>);
+
+impl Db {
+    pub fn open(app: &AppHandle) -> Self {
+        Self(Mutex::new(open_connection(app)))
+    }
+
+    fn ensure_open(&self) -> Result<(), String> {
+        self.with_conn(|_| Ok(()))
+    }
+
+    /// Runs `f` against the open connection, or returns the error the
+    /// database failed to open with.
+    pub fn with_conn<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>,
+    ) -> Result<T, String> {
+        let mut guard = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        match guard.as_mut() {
+            Ok(conn) => f(conn).map_err(|e| e.to_string()),
+            Err(e) => Err(format!("database unavailable: {}", e)),
+        }
+    }
+}
+
+pub fn db_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(data_dir::resolve(app)?.join(DB_FILE))
+}
+
+fn open_connection(app: &AppHandle) -> Result<Connection, String> {
+    let path = db_path(app)?;
+    let mut conn =
+        Connection::open(&path).map_err(|e| format!("failed to open {}: {}", path.display(), e))?;
+
+    conn.busy_timeout(Duration::from_secs(5))
+        .and_then(|_| conn.pragma_update(None, "foreign_keys", true))
+        .map_err(|e| e.to_string())?;
+    migrate(&mut conn).map_err(|e| format!("failed to migrate {}: {}", path.display(), e))?;
+
+    Ok(conn)
+}
+
+fn migrate(conn: &mut Connection) -> rusqlite::Result<()> {
+    let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", index as i64 + 1)?;
+        tx.commit()?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Transaction {
+    pub id: i64,
+    pub account_id: Option<i64>,
+    pub amount_cents: i64,
+    pub description: String,
+    pub category: Option<String>,
+    pub occurred_at: String,
+    pub created_at: String,
+}
+
+impl Transaction {
+    const COLUMNS: &'static str =
+        "id, account_id, amount_cents, description, category, occurred_at, created_at";
+
+    fn from_row(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            account_id: row.get(1)?,
+            amount_cents: row.get(2)?,
+            description: row.get(3)?,
+            category: row.get(4)?,
+            occurred_at: row.get(5)?,
+            created_at: row.get(6)?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewTransaction {
+    pub account_id: Option<i64>,
+    pub amount_cents: i64,
+    #[serde(default)]
+    pub description: String,
+    pub category: Option<String>,
+    pub occurred_at: String,
+}
+
+#[tauri::command]
+pub fn add_transaction(
+    db: State<'_, Db>,
+    transaction: NewTransaction,
+) -> Result<Transaction, String> {
+    db.with_conn(|conn| {
+        conn.execute(
+            "INSERT INTO transactions (account_id, amount_cents, description, category, occurred_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                transaction.account_id,
+                transaction.amount_cents,
+                transaction.description,
+                transaction.category,
+                transaction.occurred_at,
+            ],
+        )?;
+
+        conn.query_row(
+            &format!(
+                "SELECT {} FROM transactions WHERE id = ?1",
+                Transaction::COLUMNS
+            ),
+            [conn.last_insert_rowid()],
+            Transaction::from_row,
+        )
+    })
+}
+
+/// Lists transactions newest first, optionally limited to one account.
+#[tauri::command]
+pub fn list_transactions(
+    db: State<'_, Db>,
+    account_id: Option<i64>,
+) -> Result<Vec<Transaction>, String> {
+    db.with_conn(|conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM transactions
+             WHERE ?1 IS NULL OR account_id = ?1
+             ORDER BY occurred_at DESC, id DESC",
+            Transaction::COLUMNS
+        ))?;
+        let rows = stmt.query_map([account_id], Transaction::from_row)?;
+        rows.collect()
+    })
+}
+
+#[tauri::command]
+pub fn delete_transaction(db: State<'_, Db>, id: i64) -> Result<(), String> {
+    let deleted =
+        db.with_conn(|conn| conn.execute("DELETE FROM transactions WHERE id = ?1", [id]))?;
+    if deleted == 0 {
+        return Err(format!("no transaction with id {}", id));
+    }
+    Ok(())
+}
+
+/// Copies the database into a user-chosen folder as a timestamped `.db` file
+/// and returns its path. Uses SQLite's online backup API so the copy is
+/// consistent even if a write is in flight.
+#[tauri::command]
+pub async fn backup_database(app: AppHandle) -> Result<String, String> {
+    let db = app.state::<Db>();
+    db.ensure_open()?;
+
+    let dir = app
+        .dialog()
+        .file()
+        .set_title("Choose a folder for the database backup")
+        .blocking_pick_folder()
+        .ok_or(CANCELLED)?
+        .into_path()
+        .map_err(|e| e.to_string())?;
+
+    db.with_conn(|conn| {
+        let timestamp: String = conn.query_row(
+            "SELECT strftime('%Y%m%d-%H%M%S', 'now', 'localtime')",
+            [],
+            |row| row.get(0),
+        )?;
+        let path = dir.join(format!("richdad-{}.db", timestamp));
+        conn.backup(DatabaseName::Main, &path, None)?;
+        Ok(path.display().to_string())
+    })
+}
@@ -0,0 +1,134 @@
+// richdad:// deep links
+//
+// `richdad://budget/2024-03` maps to the route `/budget/2024-03`. A link that
+// arrives while the app is running is sent to an open window as a `deep-link`
+// event. A link the app was launched with, or one that arrives when no window
+// is left and a new one has to be opened, is held until the frontend asks for
+// it, since that window isn't listening yet.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{App, AppHandle, Emitter, Manager, State, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+const SCHEME: &str = "richdad";
+
+#[derive(Default)]
+pub struct PendingDeepLink(Mutex<Option<String>>);
+
+#[derive(Clone, Serialize)]
+struct DeepLink {
+    route: String,
+}
+
+pub fn init(app: &App) -> Result<(), Box<dyn std::error::Error>> {
+    // Bundled installs register the scheme at install time; dev builds (and
+    // AppImages on Linux) have to register it at runtime
+    #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+    app.deep_link().register_all()?;
+
+    // Cold start
+    let launch_route = app
+        .deep_link()
+        .get_current()?
+        .and_then(|urls| urls.iter().find_map(route_from_url));
+    app.manage(PendingDeepLink(Mutex::new(launch_route)));
+
+    // Warm start: delivered directly on macOS, and forwarded by the
+    // single-instance plugin on Windows/Linux
+    let handle = app.handle().clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            if let Some(route) = route_from_url(&url) {
+                if let Err(e) = open_route(&handle, route) {
+                    log::error!("Failed to open deep link {}: {}", url, e);
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+fn route_from_url(url: &Url) -> Option<String> {
+    if url.scheme() != SCHEME {
+        return None;
+    }
+
+    let path = format!("{}{}", url.host_str().unwrap_or_default(), url.path());
+    let mut route = format!("/{}", path.trim_matches('/'));
+    if let Some(query) = url.query() {
+        route.push('?');
+        route.push_str(query);
+    }
+    Some(route)
+}
+
+fn open_route(app: &AppHandle, route: String) -> Result<(), String> {
+    let target = app
+        .get_webview_window(crate::MAIN_WINDOW_LABEL)
+        .or_else(|| {
+            app.webview_windows()
+                .into_values()
+                .find(|window| crate::is_app_window(window.label()))
+        });
+
+    // The renderer doesn't route by URL, so a fresh window is opened on `/`
+    // and collects the link the same way a cold start does
+    let Some(window) = target else {
+        *app.state::<PendingDeepLink>()
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(route);
+        return crate::open_new_window(app, None, None, None);
+    };
+
+    window
+        .emit_to(window.label(), "deep-link", DeepLink { route })
+        .map_err(|e| e.to_string())?;
+    window.show().map_err(|e| e.to_string())?;
+    crate::bring_to_front(&window).map_err(|e| e.to_string())
+}
+
+/// Returns the held deep link route (from launch, or for a window opened to
+/// show one), if any. Only the first call gets it.
+#[tauri::command]
+pub fn take_pending_deep_link(state: State<'_, PendingDeepLink>) -> Option<String> {
+    state.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(url: &str) -> Option<String> {
+        route_from_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn host_and_path_form_the_route() {
+        assert_eq!(
+            route("richdad://budget/2024-03").as_deref(),
+            Some("/budget/2024-03")
+        );
+    }
+
+    #[test]
+    fn trailing_slash_is_dropped() {
+        assert_eq!(route("richdad://budget/").as_deref(), Some("/budget"));
+    }
+
+    #[test]
+    fn query_string_is_kept() {
+        assert_eq!(
+            route("richdad://budget/2024-03?tab=summary").as_deref(),
+            Some("/budget/2024-03?tab=summary")
+        );
+    }
+
+    #[test]
+    fn foreign_scheme_is_ignored() {
+        assert_eq!(route("https://budget/2024-03"), None);
+    }
+}
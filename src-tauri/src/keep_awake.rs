@@ -0,0 +1,43 @@
+// Sleep inhibition
+//
+// While enabled, an OS inhibitor keeps the system and display awake so a long
+// import or report isn't cut off. The inhibitor lives in managed state and is
+// released when it's dropped, by `set_keep_awake(false)` or by `release` on
+// `RunEvent::Exit`. Tauri quits through `process::exit`, which never drops
+// managed state, so the exit hook is what covers a frontend that forgets.
+
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Default)]
+pub struct KeepAwakeState(Mutex<Option<keepawake::KeepAwake>>);
+
+#[tauri::command]
+pub fn set_keep_awake(state: State<'_, KeepAwakeState>, enabled: bool) -> Result<(), String> {
+    let mut inhibitor = state.0.lock().unwrap_or_else(|e| e.into_inner());
+
+    if !enabled {
+        *inhibitor = None;
+        return Ok(());
+    }
+    if inhibitor.is_none() {
+        let awake = keepawake::Builder::default()
+            .display(true)
+            .idle(true)
+            .reason("Finishing a long-running task")
+            .app_name("RichDad")
+            .app_reverse_domain("com.richdad.app")
+            .create()
+            .map_err(|e| format!("failed to prevent sleep: {}", e))?;
+        *inhibitor = Some(awake);
+    }
+    Ok(())
+}
+
+/// Drops the inhibitor, if any. Called when the app exits.
+pub fn release(app: &AppHandle) {
+    if let Some(state) = app.try_state::<KeepAwakeState>() {
+        state.0.lock().unwrap_or_else(|e| e.into_inner()).take();
+    }
+}
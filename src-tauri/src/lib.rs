@@ -1,59 +1,729 @@
-use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+mod backup;
+mod clipboard;
+mod close_guard;
+mod data_dir;
+mod db;
+mod deep_link;
+#[cfg(desktop)]
+mod keep_awake;
+mod locale;
+mod logging;
+#[cfg(desktop)]
+mod menu;
+mod modal;
+mod monitors;
+mod notifications;
+mod opacity;
+mod print;
+mod recent_files;
+mod reload;
+mod settings;
+#[cfg(desktop)]
+mod shortcuts;
+mod splash;
+#[cfg(desktop)]
+mod tray;
+#[cfg(desktop)]
+mod updater;
+mod window_events;
+mod window_state;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::window::{ProgressBarState, ProgressBarStatus, ResizeDirection};
+use tauri::{
+    AppHandle, LogicalSize, Manager, RunEvent, UserAttentionType, WebviewUrl, WebviewWindow,
+    WebviewWindowBuilder, WindowEvent,
+};
+
+const MAIN_WINDOW_LABEL: &str = "main";
+const WINDOW_LABEL_PREFIX: &str = "richdad_";
+const DEFAULT_TITLE: &str = "RichDad";
+const MAX_TITLE_LEN: usize = 120;
+const MAX_WINDOWS: usize = 8;
+
+const DEFAULT_WIDTH: f64 = 1600.0;
+const DEFAULT_HEIGHT: f64 = 1000.0;
+const MIN_WIDTH: f64 = 1200.0;
+const MIN_HEIGHT: f64 = 800.0;
+const CASCADE_OFFSET: f64 = 30.0;
+// Stands in for a missing maximum dimension, since `set_max_size` takes both
+const UNBOUNDED_SIZE: f64 = 100_000.0;
+
+const MIN_ZOOM: f64 = 0.5;
+const MAX_ZOOM: f64 = 3.0;
+const ZOOM_STEP: f64 = 0.1;
+
+/// Source of window label numbers, kept in managed state. The counter only
+/// moves forward, so closing a window never frees up a label that a later
+/// window would then collide with.
+struct WindowIds(AtomicUsize);
+
+impl Default for WindowIds {
+    fn default() -> Self {
+        Self(AtomicUsize::new(1))
+    }
+}
+
+impl WindowIds {
+    fn next_id(&self) -> usize {
+        self.0.fetch_add(1, Ordering::Relaxed)
+    }
+}
 
 // Window control commands
+
+// Logged on the way out so failures reach the log file, not just the frontend.
+// Also used by the window commands in other modules.
+pub(crate) fn window_error(e: impl std::fmt::Display) -> String {
+    log::error!("Window command failed: {}", e);
+    e.to_string()
+}
+
+#[tauri::command]
+fn minimize_window(window: tauri::Window) -> Result<(), String> {
+    window.minimize().map_err(window_error)
+}
+
+// Unlike minimizing, hiding also removes the taskbar entry, which is what
+// minimize-to-tray wants
+#[tauri::command]
+fn hide_window(window: tauri::Window) -> Result<(), String> {
+    window.hide().map_err(window_error)
+}
+
+#[tauri::command]
+fn show_window(window: tauri::Window) -> Result<(), String> {
+    window.show().map_err(window_error)?;
+    window.set_focus().map_err(window_error)
+}
+
+#[tauri::command]
+fn is_visible(window: tauri::Window) -> Result<bool, String> {
+    window.is_visible().map_err(window_error)
+}
+
+/// Maximizes or restores the window and returns whether it is now maximized.
+fn toggle_maximize(window: &tauri::Window) -> Result<bool, String> {
+    if window.is_maximized().map_err(window_error)? {
+        window.unmaximize().map_err(window_error)?;
+        Ok(false)
+    } else {
+        window.maximize().map_err(window_error)?;
+        Ok(true)
+    }
+}
+
+#[tauri::command]
+fn maximize_window(window: tauri::Window) -> Result<(), String> {
+    toggle_maximize(&window).map(|_| ())
+}
+
+// Custom titlebars lose the native double-click-to-maximize gesture. Same
+// toggle as the maximize button, but the new state is pushed straight back so
+// the titlebar icon doesn't have to wait for the debounced resize event
+#[tauri::command]
+fn titlebar_double_click(window: tauri::Window) -> Result<(), String> {
+    let is_maximized = toggle_maximize(&window)?;
+    window_events::emit_state_changed(&window, is_maximized);
+    Ok(())
+}
+
+#[tauri::command]
+fn close_window(window: tauri::Window) -> Result<(), String> {
+    window.close().map_err(window_error)
+}
+
+#[tauri::command]
+fn is_maximized(window: tauri::Window) -> Result<bool, String> {
+    window.is_maximized().map_err(window_error)
+}
+
+#[tauri::command]
+fn close_window_by_label(app: AppHandle, label: String) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window with label '{}'", label))?;
+    window.close().map_err(window_error)
+}
+
+#[derive(Serialize)]
+struct WindowInfo {
+    label: String,
+    title: String,
+    is_maximized: bool,
+    is_focused: bool,
+}
+
+#[tauri::command]
+fn list_windows(app: AppHandle) -> Vec<WindowInfo> {
+    let mut windows: Vec<WindowInfo> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label != splash::SPLASH_LABEL)
+        .map(|(label, window)| WindowInfo {
+            label,
+            title: window.title().unwrap_or_default(),
+            is_maximized: window.is_maximized().unwrap_or(false),
+            is_focused: window.is_focused().unwrap_or(false),
+        })
+        .collect();
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+    windows
+}
+
+// `set_focus` alone doesn't bring back a minimized window on Windows
+fn bring_to_front(window: &WebviewWindow) -> tauri::Result<()> {
+    if window.is_minimized()? {
+        window.unminimize()?;
+    }
+    window.set_focus()
+}
+
+#[tauri::command]
+fn focus_window(app: AppHandle, label: String) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window with label '{}'", label))?;
+    bring_to_front(&window).map_err(window_error)
+}
+
+#[tauri::command]
+fn toggle_fullscreen(window: tauri::Window) -> Result<bool, String> {
+    // Fullscreen is tracked separately from maximize, so leaving it restores
+    // whatever maximized/normal state the window had before
+    let fullscreen = !window.is_fullscreen().map_err(window_error)?;
+    window.set_fullscreen(fullscreen).map_err(window_error)?;
+    Ok(fullscreen)
+}
+
+// Always-on-top is a property of each native window, so toggling it on one
+// window leaves any others spawned by `create_new_window` untouched
+#[tauri::command]
+fn set_always_on_top(window: tauri::Window, enabled: bool) -> Result<(), String> {
+    window.set_always_on_top(enabled).map_err(window_error)
+}
+
+#[tauri::command]
+fn is_always_on_top(window: tauri::Window) -> Result<bool, String> {
+    window.is_always_on_top().map_err(window_error)
+}
+
+// Keeps utility popups out of the taskbar. macOS has no per-window taskbar
+// entry (the dock icon belongs to the app), so there it does nothing
+#[tauri::command]
+fn set_skip_taskbar(window: tauri::Window, skip: bool) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let _ = (window, skip);
+        Ok(())
+    }
+    #[cfg(not(target_os = "macos"))]
+    window.set_skip_taskbar(skip).map_err(window_error)
+}
+
+// Drags are started from the custom titlebar's mousedown handler. If the
+// button has already been released by the time the command runs, the platform
+// refuses to start the drag; nothing is lost, so that is logged, not surfaced
+#[tauri::command]
+fn start_dragging(window: tauri::Window) -> Result<(), String> {
+    if let Err(e) = window.start_dragging() {
+        log::warn!("Window drag did not start: {}", e);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn start_resize_dragging(window: tauri::Window, direction: ResizeDirection) -> Result<(), String> {
+    if let Err(e) = window.start_resize_dragging(direction) {
+        log::warn!("Window resize drag did not start: {}", e);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn restore_window(window: tauri::Window) -> Result<(), String> {
+    window.unminimize().map_err(window_error)?;
+    window.set_focus().map_err(window_error)
+}
+
+#[tauri::command]
+fn restore_window_by_label(app: AppHandle, label: String) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window with label '{}'", label))?;
+    bring_to_front(&window).map_err(window_error)
+}
+
 #[tauri::command]
-fn minimize_window(window: tauri::Window) {
-    window.minimize().unwrap();
+fn center_window(window: tauri::Window) -> Result<(), String> {
+    // Centering a maximized window has no visible effect
+    if window.is_maximized().map_err(window_error)? {
+        window.unmaximize().map_err(window_error)?;
+    }
+    window.center().map_err(window_error)
+}
+
+/// Replaces the window's size limits (logical pixels) so each route can set
+/// its own, e.g. a compact ticker view below the dashboard's minimum. With no
+/// maximum the window can grow freely.
+#[tauri::command]
+fn set_size_constraints(
+    window: tauri::Window,
+    min_w: f64,
+    min_h: f64,
+    max_w: Option<f64>,
+    max_h: Option<f64>,
+) -> Result<(), String> {
+    let is_valid = |size: f64| size.is_finite() && size > 0.0;
+    if ![min_w, min_h]
+        .into_iter()
+        .chain(max_w)
+        .chain(max_h)
+        .all(is_valid)
+    {
+        return Err("window size limits must be positive".into());
+    }
+    if max_w.is_some_and(|max_w| max_w < min_w) || max_h.is_some_and(|max_h| max_h < min_h) {
+        return Err(format!(
+            "minimum size {}x{} is larger than the maximum",
+            min_w, min_h
+        ));
+    }
+
+    window
+        .set_min_size(Some(LogicalSize::new(min_w, min_h)))
+        .map_err(window_error)?;
+    let max_size = match (max_w, max_h) {
+        (None, None) => None,
+        (max_w, max_h) => Some(LogicalSize::new(
+            max_w.unwrap_or(UNBOUNDED_SIZE),
+            max_h.unwrap_or(UNBOUNDED_SIZE),
+        )),
+    };
+    window.set_max_size(max_size).map_err(window_error)
 }
 
 #[tauri::command]
-fn maximize_window(window: tauri::Window) {
-    if window.is_maximized().unwrap() {
-        window.unmaximize().unwrap();
+fn set_window_title(window: tauri::Window, title: String) -> Result<(), String> {
+    let title: String = title.trim().chars().take(MAX_TITLE_LEN).collect();
+    let title = if title.is_empty() {
+        DEFAULT_TITLE
     } else {
-        window.maximize().unwrap();
+        &title
+    };
+    window.set_title(title).map_err(window_error)
+}
+
+#[tauri::command]
+fn get_system_theme(window: tauri::Window) -> String {
+    let theme = window.theme().unwrap_or(tauri::Theme::Light);
+    window_events::theme_name(theme).to_string()
+}
+
+fn apply_zoom(window: &WebviewWindow, factor: f64) -> Result<f64, String> {
+    // Rounded to one decimal so repeated steps don't drift
+    let factor = (factor.clamp(MIN_ZOOM, MAX_ZOOM) * 10.0).round() / 10.0;
+    window.set_zoom(factor).map_err(window_error)?;
+    window_state::record_zoom(window.app_handle(), window.label(), factor)?;
+    Ok(factor)
+}
+
+#[tauri::command]
+fn set_zoom(window: WebviewWindow, factor: f64) -> Result<(), String> {
+    apply_zoom(&window, factor).map(|_| ())
+}
+
+#[tauri::command]
+fn zoom_in(window: WebviewWindow) -> Result<f64, String> {
+    let current = window_state::zoom(window.app_handle(), window.label());
+    apply_zoom(&window, current + ZOOM_STEP)
+}
+
+#[tauri::command]
+fn zoom_out(window: WebviewWindow) -> Result<f64, String> {
+    let current = window_state::zoom(window.app_handle(), window.label());
+    apply_zoom(&window, current - ZOOM_STEP)
+}
+
+/// Shows `progress` (a fraction from 0.0 to 1.0) on the taskbar/dock icon, or
+/// clears it when `None`. Platforms without taskbar progress (most Linux
+/// desktops) just ignore it.
+#[tauri::command]
+fn set_progress_bar(window: tauri::Window, progress: Option<f64>) -> Result<(), String> {
+    let state = match progress {
+        Some(fraction) => ProgressBarState {
+            status: Some(ProgressBarStatus::Normal),
+            progress: Some((fraction.clamp(0.0, 1.0) * 100.0).round() as u64),
+        },
+        None => ProgressBarState {
+            status: Some(ProgressBarStatus::None),
+            progress: None,
+        },
+    };
+
+    if let Err(e) = window.set_progress_bar(state) {
+        log::warn!("Taskbar progress is unavailable: {}", e);
     }
+    Ok(())
+}
+
+fn request_attention(window: &tauri::Window, critical: bool) -> tauri::Result<()> {
+    let kind = if critical {
+        UserAttentionType::Critical
+    } else {
+        UserAttentionType::Informational
+    };
+    window.request_user_attention(Some(kind))
 }
 
+/// Flashes the taskbar icon (bounces the dock icon on macOS) until the
+/// window is focused.
 #[tauri::command]
-fn close_window(window: tauri::Window) {
-    window.close().unwrap();
+fn flash_window(window: tauri::Window, critical: bool) -> Result<(), String> {
+    request_attention(&window, critical).map_err(window_error)
 }
 
 #[tauri::command]
-fn is_maximized(window: tauri::Window) -> bool {
-    window.is_maximized().unwrap()
+fn clear_window_attention(window: tauri::Window) -> Result<(), String> {
+    window.request_user_attention(None).map_err(window_error)
 }
 
+// Async so the window is built off the IPC handler; building a webview from a
+// synchronous command deadlocks on Windows
 #[tauri::command]
-fn create_new_window(app: AppHandle) -> Result<(), String> {
-    let window_count = app.webview_windows().len();
-    let label = format!("richdad_{}", window_count);
+async fn create_new_window(
+    app: AppHandle,
+    route: Option<String>,
+    width: Option<f64>,
+    height: Option<f64>,
+) -> Result<(), String> {
+    open_new_window(&app, route, width, height)
+}
+
+/// Opens a regular app window on `route`. Shared by the command and by the
+/// menu, tray, shortcut and deep-link handlers, none of which run inside an
+/// IPC handler.
+fn open_new_window(
+    app: &AppHandle,
+    route: Option<String>,
+    width: Option<f64>,
+    height: Option<f64>,
+) -> Result<(), String> {
+    let route = route.unwrap_or_else(|| "/".into());
+    if !route.starts_with('/') {
+        return Err(format!("route must start with '/': {}", route));
+    }
+
+    if window_count(app) >= MAX_WINDOWS {
+        return Err("window limit reached".into());
+    }
+
+    let label = format!(
+        "{}{}",
+        WINDOW_LABEL_PREFIX,
+        app.state::<WindowIds>().next_id()
+    );
+
+    // An explicit size from the caller wins over whatever was saved for this label
+    let (width, height) = match (width, height) {
+        (None, None) => window_state::saved(app, &label)
+            .map(|state| (state.width, state.height))
+            .unwrap_or((DEFAULT_WIDTH, DEFAULT_HEIGHT)),
+        (width, height) => (
+            width.unwrap_or(DEFAULT_WIDTH).max(MIN_WIDTH),
+            height.unwrap_or(DEFAULT_HEIGHT).max(MIN_HEIGHT),
+        ),
+    };
+
+    let mut builder = WebviewWindowBuilder::new(app, &label, WebviewUrl::App(route.into()))
+        .title(DEFAULT_TITLE)
+        .inner_size(width, height)
+        .min_inner_size(MIN_WIDTH, MIN_HEIGHT)
+        .resizable(true);
+
+    // Cascading is only for labels with no saved position to go back to
+    let position =
+        window_state::saved_position(app, &label).or_else(|| cascade_position(app, width, height));
+    if let Some((x, y)) = position {
+        builder = builder.position(x, y);
+    }
+
+    let window = builder.build().map_err(window_error)?;
+    window_state::restore_after_build(&window).map_err(window_error)?;
+
+    Ok(())
+}
+
+/// Whether `label` is the main window or one opened by `create_new_window`,
+/// as opposed to the splash screen or a modal.
+fn is_app_window(label: &str) -> bool {
+    label == MAIN_WINDOW_LABEL || label.starts_with(WINDOW_LABEL_PREFIX)
+}
+
+/// Open windows counted against `MAX_WINDOWS`. The splash screen doesn't
+/// count; it is gone as soon as startup finishes.
+fn window_count(app: &AppHandle) -> usize {
+    app.webview_windows()
+        .keys()
+        .filter(|label| *label != splash::SPLASH_LABEL)
+        .count()
+}
+
+/// Takes the app-wide menu bar off a window that shouldn't have one, like the
+/// splash screen or a modal. Windows and Linux attach that menu to every
+/// window; macOS shows it in the global menu bar, so there is nothing to do.
+fn remove_menu_bar(window: &WebviewWindow) {
+    #[cfg(all(desktop, not(target_os = "macos")))]
+    if let Err(e) = window.remove_menu() {
+        log::error!("Failed to remove menu bar from {}: {}", window.label(), e);
+    }
+    #[cfg(not(all(desktop, not(target_os = "macos"))))]
+    let _ = window;
+}
+
+/// Places a new window on the monitor under the cursor, stepping each one down
+/// and right by `CASCADE_OFFSET` while keeping it inside the monitor's work
+/// area. Returns logical coordinates, or `None` to let the OS decide.
+fn cascade_position(app: &AppHandle, width: f64, height: f64) -> Option<(f64, f64)> {
+    let cursor = app.cursor_position().ok()?;
+    let monitors = app.available_monitors().ok()?;
+    let monitor = monitors
+        .iter()
+        .find(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+            cursor.x >= position.x as f64
+                && cursor.x < position.x as f64 + size.width as f64
+                && cursor.y >= position.y as f64
+                && cursor.y < position.y as f64 + size.height as f64
+        })
+        .or_else(|| monitors.first())?;
+
+    let scale = monitor.scale_factor();
+    let area = monitor.work_area();
+    let origin = area.position.to_logical::<f64>(scale);
+    let size = area.size.to_logical::<f64>(scale);
+
+    let open = app
+        .webview_windows()
+        .keys()
+        .filter(|label| is_app_window(label))
+        .count();
+    let step = open as f64 * CASCADE_OFFSET;
+    let x = (origin.x + step).min(origin.x + (size.width - width).max(0.0));
+    let y = (origin.y + step).min(origin.y + (size.height - height).max(0.0));
+    Some((x, y))
+}
 
-    WebviewWindowBuilder::new(&app, label, WebviewUrl::App("/".into()))
-        .title("RichDad")
-        .inner_size(1600.0, 1000.0)
-        .min_inner_size(1200.0, 800.0)
-        .resizable(true)
+#[tauri::command]
+fn quit_app(app: AppHandle) {
+    app.exit(0);
+}
+
+/// Builds the main window from its `tauri.conf.json` entry, applying any saved
+/// bounds. The entry is declared with `create: false` so this is the only
+/// place it gets created, and starts hidden until the splash screen is done.
+fn build_main_window(app: &AppHandle) -> Result<WebviewWindow, String> {
+    let config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == MAIN_WINDOW_LABEL)
+        .cloned()
+        .ok_or("main window missing from tauri.conf.json")?;
+    let builder = WebviewWindowBuilder::from_config(app, &config).map_err(|e| e.to_string())?;
+    let window = window_state::restore(builder, app, MAIN_WINDOW_LABEL)
         .build()
         .map_err(|e| e.to_string())?;
+    window_state::restore_after_build(&window).map_err(|e| e.to_string())?;
+    Ok(window)
+}
 
-    Ok(())
+/// Shows and focuses the main window, recreating it if it has been closed.
+///
+/// If the main window is gone but another window was hidden to the tray, that
+/// window is brought back instead of opening a fresh one.
+fn show_main_window(app: &AppHandle) -> Result<(), String> {
+    let existing = app.get_webview_window(MAIN_WINDOW_LABEL).or_else(|| {
+        app.webview_windows()
+            .into_values()
+            .find(|window| is_app_window(window.label()))
+    });
+    let window = match existing {
+        Some(window) => window,
+        None => build_main_window(app)?,
+    };
+    window.show().map_err(|e| e.to_string())?;
+    bring_to_front(&window).map_err(|e| e.to_string())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    // Must be the first plugin registered so a second launch exits before
+    // any other setup runs
+    #[cfg(desktop)]
+    {
+        builder = builder
+            .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+                if let Err(e) = show_main_window(app) {
+                    log::error!("Failed to focus existing instance: {}", e);
+                }
+            }))
+            .plugin(tauri_plugin_updater::Builder::new().build());
+    }
+
+    builder
+        .plugin(logging::plugin())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_http::init())
-        .invoke_handler(tauri::generate_handler![
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .setup(|app| {
+            app.manage::<settings::SettingsState>(Mutex::new(settings::load(app.handle())));
+            app.manage(db::Db::open(app.handle()));
+            app.manage(WindowIds::default());
+            #[cfg(desktop)]
+            app.manage(keep_awake::KeepAwakeState::default());
+
+            #[cfg(desktop)]
+            {
+                app.set_menu(menu::build(app.handle())?)?;
+                app.on_menu_event(menu::handle_event);
+            }
+
+            build_main_window(app.handle())?;
+            splash::init(app)?;
+            deep_link::init(app)?;
+
+            #[cfg(desktop)]
+            {
+                tray::init(app)?;
+                shortcuts::init(app)?;
+                updater::check_in_background(app.handle());
+            }
+
+            Ok(())
+        })
+        .on_window_event(|window, event| match event {
+            WindowEvent::Moved(_) => window_state::track(window),
+            WindowEvent::ThemeChanged(theme) => window_events::on_theme_changed(window, *theme),
+            WindowEvent::Resized(_) => {
+                window_state::track(window);
+                window_events::on_resized(window);
+            }
+            WindowEvent::CloseRequested { api, .. } => {
+                if let Err(e) = window_state::save(window) {
+                    log::error!("Failed to save window state: {}", e);
+                }
+
+                // Closing the last window hides it to the tray when the user
+                // has opted in, so background reminders keep running
+                let app = window.app_handle();
+                if settings::current(app).close_to_tray && close_guard::is_last_window(window) {
+                    api.prevent_close();
+                    if let Err(e) = window.hide() {
+                        log::error!("Failed to hide window to tray: {}", e);
+                    }
+                } else {
+                    close_guard::on_close_requested(window, api);
+                }
+            }
+            WindowEvent::Focused(true) => {
+                if let Err(e) = window.request_user_attention(None) {
+                    log::error!("Failed to clear attention request: {}", e);
+                }
+            }
+            WindowEvent::Destroyed => {
+                close_guard::forget(window);
+                modal::on_destroyed(window);
+            }
+            _ => {}
+        })
+        .invoke_handler(logging::log_invocations(tauri::generate_handler![
             minimize_window,
+            hide_window,
+            show_window,
+            is_visible,
             maximize_window,
+            titlebar_double_click,
             close_window,
+            close_window_by_label,
+            list_windows,
+            focus_window,
             is_maximized,
-            create_new_window
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+            toggle_fullscreen,
+            set_always_on_top,
+            is_always_on_top,
+            set_skip_taskbar,
+            start_dragging,
+            start_resize_dragging,
+            restore_window,
+            restore_window_by_label,
+            center_window,
+            set_size_constraints,
+            set_window_title,
+            get_system_theme,
+            locale::get_locale,
+            set_zoom,
+            zoom_in,
+            zoom_out,
+            reload::reload_window,
+            set_progress_bar,
+            flash_window,
+            clear_window_attention,
+            opacity::set_window_opacity,
+            monitors::snap_window,
+            monitors::list_monitors,
+            monitors::move_to_monitor,
+            monitors::is_on_primary_monitor,
+            create_new_window,
+            modal::create_modal_window,
+            quit_app,
+            splash::close_splashscreen,
+            close_guard::acknowledge_close,
+            close_guard::confirm_close,
+            close_guard::cancel_close,
+            notifications::notify,
+            print::print_to_pdf,
+            clipboard::copy_to_clipboard,
+            clipboard::read_clipboard,
+            backup::export_data,
+            backup::import_data,
+            backup::export_data_encrypted,
+            backup::import_data_encrypted,
+            recent_files::get_recent_files,
+            db::add_transaction,
+            db::list_transactions,
+            db::delete_transaction,
+            db::backup_database,
+            data_dir::get_data_dir,
+            data_dir::open_data_dir,
+            deep_link::take_pending_deep_link,
+            #[cfg(desktop)]
+            updater::install_update,
+            #[cfg(desktop)]
+            keep_awake::set_keep_awake,
+            settings::get_settings,
+            settings::update_settings,
+            logging::get_log_path
+        ]))
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| match event {
+            // `app.exit` (Quit in the menu and tray) skips CloseRequested, so
+            // the open windows' bounds are saved here instead
+            RunEvent::ExitRequested { .. } => window_state::save_all(app),
+            #[cfg(desktop)]
+            RunEvent::Exit => keep_awake::release(app),
+            _ => {}
+        });
 }
@@ -0,0 +1,104 @@
+// System locale and default currency
+//
+// Lets the frontend format money correctly on first run, before the user has
+// picked a currency themselves.
+
+use serde::Serialize;
+
+const FALLBACK_LOCALE: &str = "en-US";
+const FALLBACK_CURRENCY: &str = "USD";
+
+#[derive(Serialize)]
+pub struct LocaleInfo {
+    locale: String,
+    currency: &'static str,
+}
+
+/// Pulls the region out of tags like `en-US`, `de_DE.UTF-8` or `zh-Hant-TW`.
+fn region(locale: &str) -> Option<String> {
+    locale
+        .split(['.', '@'])
+        .next()?
+        .split(['-', '_'])
+        .skip(1)
+        .find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|part| part.to_ascii_uppercase())
+}
+
+/// Best-effort currency for a region; anything unlisted falls back to USD.
+fn currency_for_region(region: &str) -> &'static str {
+    match region {
+        "AT" | "BE" | "CY" | "DE" | "EE" | "ES" | "FI" | "FR" | "GR" | "HR" | "IE" | "IT"
+        | "LT" | "LU" | "LV" | "MT" | "NL" | "PT" | "SI" | "SK" => "EUR",
+        "GB" => "GBP",
+        "CA" => "CAD",
+        "AU" => "AUD",
+        "NZ" => "NZD",
+        "JP" => "JPY",
+        "CN" => "CNY",
+        "HK" => "HKD",
+        "TW" => "TWD",
+        "KR" => "KRW",
+        "IN" => "INR",
+        "SG" => "SGD",
+        "CH" | "LI" => "CHF",
+        "SE" => "SEK",
+        "NO" => "NOK",
+        "DK" => "DKK",
+        "PL" => "PLN",
+        "CZ" => "CZK",
+        "HU" => "HUF",
+        "RO" => "RON",
+        "TR" => "TRY",
+        "IL" => "ILS",
+        "ZA" => "ZAR",
+        "BR" => "BRL",
+        "MX" => "MXN",
+        "AR" => "ARS",
+        "CL" => "CLP",
+        "CO" => "COP",
+        "AE" => "AED",
+        "SA" => "SAR",
+        _ => FALLBACK_CURRENCY,
+    }
+}
+
+#[tauri::command]
+pub fn get_locale() -> LocaleInfo {
+    let locale = sys_locale::get_locale().unwrap_or_else(|| FALLBACK_LOCALE.into());
+    let currency = region(&locale)
+        .map(|region| currency_for_region(&region))
+        .unwrap_or(FALLBACK_CURRENCY);
+
+    LocaleInfo { locale, currency }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_from_bcp47_tag() {
+        assert_eq!(region("en-US").as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn region_from_posix_locale() {
+        assert_eq!(region("de_DE.UTF-8").as_deref(), Some("DE"));
+    }
+
+    #[test]
+    fn script_subtag_is_skipped() {
+        assert_eq!(region("zh-Hant-TW").as_deref(), Some("TW"));
+    }
+
+    #[test]
+    fn numeric_region_is_not_a_country() {
+        assert_eq!(region("es-419"), None);
+    }
+
+    #[test]
+    fn bare_language_has_no_region() {
+        assert_eq!(region("fr"), None);
+    }
+}
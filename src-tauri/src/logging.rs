@@ -0,0 +1,53 @@
+// File logging
+//
+// Everything logged through the `log` macros goes to stdout and to a file in
+// the app log dir. The file is rotated once it reaches `MAX_LOG_BYTES`, and
+// only the newest `KEPT_LOG_FILES` rotated files are kept, so a long-running
+// install stays bounded while the lead-up to a problem is still on disk.
+
+use tauri::ipc::Invoke;
+use tauri::plugin::TauriPlugin;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
+
+const LOG_FILE_NAME: &str = "richdad";
+const MAX_LOG_BYTES: u128 = 5 * 1024 * 1024;
+const KEPT_LOG_FILES: usize = 3;
+
+pub fn plugin<R: Runtime>() -> TauriPlugin<R> {
+    tauri_plugin_log::Builder::new()
+        .clear_targets()
+        .target(Target::new(TargetKind::Stdout))
+        .target(Target::new(TargetKind::LogDir {
+            file_name: Some(LOG_FILE_NAME.into()),
+        }))
+        .level(log::LevelFilter::Info)
+        .max_file_size(MAX_LOG_BYTES)
+        .rotation_strategy(RotationStrategy::KeepSome(KEPT_LOG_FILES))
+        .build()
+}
+
+/// Wraps the command handler so every invocation is logged with the window
+/// it came from. Arguments are left out; some carry user data.
+pub fn log_invocations(
+    handler: impl Fn(Invoke) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        log::info!(
+            "{} invoked by {}",
+            invoke.message.command(),
+            invoke.message.webview_ref().label()
+        );
+        handler(invoke)
+    }
+}
+
+/// Returns the path of the current log file, for "Copy diagnostics".
+#[tauri::command]
+pub fn get_log_path(app: AppHandle) -> Result<String, String> {
+    let dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    Ok(dir
+        .join(format!("{}.log", LOG_FILE_NAME))
+        .display()
+        .to_string())
+}
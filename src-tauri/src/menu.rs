@@ -0,0 +1,103 @@
+// Application menu bar
+//
+// Set as the app-wide menu, which macOS shows in the global menu bar and
+// Windows/Linux attach to every window. Menu actions route through the same
+// commands the custom titlebar uses.
+
+use tauri::menu::{Menu, MenuBuilder, MenuEvent, MenuItemBuilder, SubmenuBuilder};
+use tauri::{AppHandle, Manager, Window};
+
+const MENU_NEW_WINDOW: &str = "menu_new_window";
+const MENU_CLOSE_WINDOW: &str = "menu_close_window";
+const MENU_QUIT: &str = "menu_quit";
+const MENU_MINIMIZE: &str = "menu_minimize";
+const MENU_MAXIMIZE: &str = "menu_maximize";
+
+pub fn build(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let new_window = MenuItemBuilder::with_id(MENU_NEW_WINDOW, "New Window")
+        .accelerator("CmdOrCtrl+N")
+        .build(app)?;
+    let close_window = MenuItemBuilder::with_id(MENU_CLOSE_WINDOW, "Close Window")
+        .accelerator("CmdOrCtrl+W")
+        .build(app)?;
+    let quit = MenuItemBuilder::with_id(MENU_QUIT, "Quit")
+        .accelerator("CmdOrCtrl+Q")
+        .build(app)?;
+    let minimize = MenuItemBuilder::with_id(MENU_MINIMIZE, "Minimize")
+        .accelerator("CmdOrCtrl+M")
+        .build(app)?;
+    let maximize = MenuItemBuilder::with_id(MENU_MAXIMIZE, "Zoom").build(app)?;
+
+    let file = SubmenuBuilder::new(app, "File")
+        .item(&new_window)
+        .item(&close_window)
+        .separator()
+        .item(&quit)
+        .build()?;
+
+    // Replacing the default menu drops its Edit entries, which macOS needs for
+    // copy/paste shortcuts to reach the webview's text inputs
+    let edit = SubmenuBuilder::new(app, "Edit")
+        .undo()
+        .redo()
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .select_all()
+        .build()?;
+
+    let window = SubmenuBuilder::new(app, "Window")
+        .item(&minimize)
+        .item(&maximize)
+        .build()?;
+
+    let menu = MenuBuilder::new(app);
+
+    // macOS always treats the first submenu as the application menu
+    #[cfg(target_os = "macos")]
+    let menu = menu.item(
+        &SubmenuBuilder::new(app, "RichDad")
+            .about(None)
+            .separator()
+            .hide()
+            .hide_others()
+            .show_all()
+            .build()?,
+    );
+
+    menu.item(&file).item(&edit).item(&window).build()
+}
+
+pub fn handle_event(app: &AppHandle, event: MenuEvent) {
+    let result = match event.id.as_ref() {
+        MENU_NEW_WINDOW => crate::open_new_window(app, None, None, None),
+        MENU_QUIT => {
+            crate::quit_app(app.clone());
+            Ok(())
+        }
+        MENU_CLOSE_WINDOW => with_focused_window(app, crate::close_window),
+        MENU_MINIMIZE => with_focused_window(app, crate::minimize_window),
+        MENU_MAXIMIZE => with_focused_window(app, crate::maximize_window),
+        _ => Ok(()),
+    };
+
+    if let Err(e) = result {
+        log::error!("Menu action failed: {}", e);
+    }
+}
+
+fn with_focused_window(
+    app: &AppHandle,
+    action: fn(Window) -> Result<(), String>,
+) -> Result<(), String> {
+    let focused = app
+        .webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false));
+
+    match focused {
+        Some(window) => action(window.as_ref().window()),
+        None => Ok(()),
+    }
+}
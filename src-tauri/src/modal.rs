@@ -0,0 +1,117 @@
+// Modal windows
+//
+// A modal is an undecorated window owned by another one (its parent). The
+// parent is disabled while the modal is open, so it can't be interacted with
+// until the modal closes; then it is re-enabled and refocused. Closing the
+// parent closes its modals too.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder, Window};
+
+const MODAL_WIDTH: f64 = 560.0;
+const MODAL_HEIGHT: f64 = 640.0;
+
+// Parent label of each open modal, keyed by the modal's label
+static PARENTS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+// Async for the same reason as `create_new_window`: building a webview from a
+// synchronous command deadlocks on Windows
+#[tauri::command]
+pub async fn create_modal_window(
+    app: AppHandle,
+    parent_label: String,
+    route: String,
+) -> Result<(), String> {
+    if !route.starts_with('/') {
+        return Err(format!("route must start with '/': {}", route));
+    }
+
+    let parent = app
+        .get_webview_window(&parent_label)
+        .ok_or_else(|| format!("no window with label {}", parent_label))?;
+
+    if crate::window_count(&app) >= crate::MAX_WINDOWS {
+        return Err("window limit reached".into());
+    }
+
+    let label = format!("modal_{}", app.state::<crate::WindowIds>().next_id());
+
+    let mut builder = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(route.into()))
+        .title(crate::DEFAULT_TITLE)
+        .inner_size(MODAL_WIDTH, MODAL_HEIGHT)
+        .decorations(false)
+        .resizable(false)
+        .minimizable(false)
+        .parent(&parent)
+        .map_err(crate::window_error)?;
+
+    if let Some((x, y)) = centered_over(&parent) {
+        builder = builder.position(x, y);
+    }
+
+    let modal = builder.build().map_err(crate::window_error)?;
+    crate::remove_menu_bar(&modal);
+
+    PARENTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(label, parent_label);
+
+    if let Err(e) = parent.set_enabled(false) {
+        log::error!("Failed to disable modal parent: {}", e);
+    }
+    Ok(())
+}
+
+/// Logical position that centers a modal over `parent`, or `None` to let the
+/// OS decide.
+fn centered_over(parent: &WebviewWindow) -> Option<(f64, f64)> {
+    let scale = parent.scale_factor().ok()?;
+    let position = parent.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = parent.outer_size().ok()?.to_logical::<f64>(scale);
+
+    Some((
+        position.x + (size.width - MODAL_WIDTH) / 2.0,
+        position.y + (size.height - MODAL_HEIGHT) / 2.0,
+    ))
+}
+
+/// Hands focus back to the parent of a closed modal, and closes the modals of
+/// a closed parent. Called when any window is destroyed.
+pub fn on_destroyed(window: &Window) {
+    let app = window.app_handle();
+    let label = window.label();
+
+    // Collected up front so the lock isn't held while other windows are
+    // torn down, which fires this handler again
+    let (parent, children) = {
+        let mut parents = PARENTS.lock().unwrap_or_else(|e| e.into_inner());
+        let parent = parents.remove(label);
+        let children: Vec<String> = parents
+            .iter()
+            .filter(|(_, parent)| *parent == label)
+            .map(|(child, _)| child.clone())
+            .collect();
+        for child in &children {
+            parents.remove(child);
+        }
+        (parent, children)
+    };
+
+    if let Some(parent) = parent.and_then(|label| app.get_webview_window(&label)) {
+        if let Err(e) = parent.set_enabled(true).and_then(|_| parent.set_focus()) {
+            log::error!("Failed to refocus modal parent: {}", e);
+        }
+    }
+
+    for child in children
+        .iter()
+        .filter_map(|label| app.get_webview_window(label))
+    {
+        if let Err(e) = child.destroy() {
+            log::error!("Failed to close modal: {}", e);
+        }
+    }
+}
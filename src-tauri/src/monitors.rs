@@ -0,0 +1,153 @@
+// Monitor-aware window placement
+
+use serde::Serialize;
+use tauri::{AppHandle, Monitor, PhysicalPosition, PhysicalSize, Window};
+
+/// A connected display. Position and size are in physical pixels.
+#[derive(Serialize)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+}
+
+impl From<&Monitor> for MonitorInfo {
+    fn from(monitor: &Monitor) -> Self {
+        Self {
+            name: monitor.name().cloned(),
+            x: monitor.position().x,
+            y: monitor.position().y,
+            width: monitor.size().width,
+            height: monitor.size().height,
+            scale_factor: monitor.scale_factor(),
+        }
+    }
+}
+
+/// Lists connected monitors in the order `move_to_monitor` indexes them.
+#[tauri::command]
+pub fn list_monitors(app: AppHandle) -> Vec<MonitorInfo> {
+    app.available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(MonitorInfo::from)
+        .collect()
+}
+
+/// Moves the window to the monitor at `index` in `list_monitors`, keeping its
+/// relative position within the work area where it fits. A maximized window
+/// is maximized again on the new monitor.
+#[tauri::command]
+pub fn move_to_monitor(window: Window, index: usize) -> Result<(), String> {
+    let monitors = window.available_monitors().map_err(crate::window_error)?;
+    let target = monitors.get(index).ok_or_else(|| {
+        format!(
+            "no monitor at index {} ({} connected)",
+            index,
+            monitors.len()
+        )
+    })?;
+
+    let was_maximized = window.is_maximized().map_err(crate::window_error)?;
+    if was_maximized {
+        window.unmaximize().map_err(crate::window_error)?;
+    }
+
+    let position = window.outer_position().map_err(crate::window_error)?;
+    let size = window.outer_size().map_err(crate::window_error)?;
+    let area = target.work_area();
+
+    // Fraction of the way across the current work area, or centered when the
+    // window isn't on any monitor right now
+    let (fx, fy) = match window.current_monitor().map_err(crate::window_error)? {
+        Some(current) => {
+            let current = current.work_area();
+            (
+                (position.x - current.position.x) as f64 / current.size.width as f64,
+                (position.y - current.position.y) as f64 / current.size.height as f64,
+            )
+        }
+        None => (0.5, 0.5),
+    };
+
+    let free_width = area.size.width.saturating_sub(size.width) as f64;
+    let free_height = area.size.height.saturating_sub(size.height) as f64;
+    let x = area.position.x + (fx * area.size.width as f64).clamp(0.0, free_width) as i32;
+    let y = area.position.y + (fy * area.size.height as f64).clamp(0.0, free_height) as i32;
+
+    window
+        .set_position(PhysicalPosition::new(x, y))
+        .map_err(crate::window_error)?;
+    if was_maximized {
+        window.maximize().map_err(crate::window_error)?;
+    }
+    Ok(())
+}
+
+/// Tiles the window onto one half of its current monitor's work area.
+///
+/// Everything is computed in physical pixels so the two halves meet exactly,
+/// with no gap from rounding at fractional scale factors. The window's min
+/// size still applies, so a half narrower than that is clamped by the OS.
+#[tauri::command]
+pub fn snap_window(window: Window, side: String) -> Result<(), String> {
+    let monitor = window
+        .current_monitor()
+        .map_err(crate::window_error)?
+        .ok_or("window is not on any monitor")?;
+
+    if window.is_maximized().map_err(crate::window_error)? {
+        window.unmaximize().map_err(crate::window_error)?;
+    }
+
+    let area = monitor.work_area();
+    let (x, y) = (area.position.x, area.position.y);
+    let (width, height) = (area.size.width, area.size.height);
+    let (half_width, half_height) = (width / 2, height / 2);
+
+    let (position, size) = match side.as_str() {
+        "left" => ((x, y), (half_width, height)),
+        "right" => ((x + half_width as i32, y), (width - half_width, height)),
+        "top" => ((x, y), (width, half_height)),
+        "bottom" => ((x, y + half_height as i32), (width, height - half_height)),
+        _ => return Err(format!("unknown side '{}'", side)),
+    };
+
+    // `set_size` sets the inner size, so take the frame off to keep the outer
+    // edge on the half boundary
+    let outer = window.outer_size().map_err(crate::window_error)?;
+    let inner = window.inner_size().map_err(crate::window_error)?;
+    let frame_width = outer.width.saturating_sub(inner.width);
+    let frame_height = outer.height.saturating_sub(inner.height);
+
+    window
+        .set_position(PhysicalPosition::new(position.0, position.1))
+        .map_err(crate::window_error)?;
+    window
+        .set_size(PhysicalSize::new(
+            size.0.saturating_sub(frame_width),
+            size.1.saturating_sub(frame_height),
+        ))
+        .map_err(crate::window_error)
+}
+
+/// Whether the window is on the primary monitor. Fails while the current
+/// monitor can't be resolved, e.g. mid-move between displays.
+#[tauri::command]
+pub fn is_on_primary_monitor(window: Window) -> Result<bool, String> {
+    let current = window
+        .current_monitor()
+        .map_err(crate::window_error)?
+        .ok_or("window is not on any monitor")?;
+    let Some(primary) = window.primary_monitor().map_err(crate::window_error)? else {
+        return Ok(false);
+    };
+
+    // `Monitor` has no equality; name and bounds identify it well enough
+    Ok(current.name() == primary.name()
+        && current.position() == primary.position()
+        && current.size() == primary.size())
+}
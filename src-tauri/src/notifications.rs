@@ -0,0 +1,49 @@
+// Native desktop notifications
+//
+// Used for bill-due reminders, which need to reach the user even while every
+// window is hidden to the tray.
+//
+// The notification plugin doesn't report clicks back on desktop, so there is
+// no hook to focus a window from here; the OS brings the app forward on click
+// where it supports that, and the tray restores the main window otherwise.
+// The main window also requests attention so a missed notification still
+// leaves the taskbar icon flashing.
+
+use tauri::plugin::PermissionState;
+use tauri::{AppHandle, Manager, UserAttentionType};
+use tauri_plugin_notification::NotificationExt;
+
+#[tauri::command]
+pub fn notify(app: AppHandle, title: String, body: String) -> Result<(), String> {
+    let notification = app.notification();
+
+    let mut permission = notification.permission_state().map_err(|e| e.to_string())?;
+    if matches!(
+        permission,
+        PermissionState::Prompt | PermissionState::PromptWithRationale
+    ) {
+        permission = notification
+            .request_permission()
+            .map_err(|e| e.to_string())?;
+    }
+    if !matches!(permission, PermissionState::Granted) {
+        return Err(
+            "notification permission denied; enable notifications for RichDad in system settings"
+                .into(),
+        );
+    }
+
+    notification
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+        .map_err(|e| e.to_string())?;
+
+    if let Some(window) = app.get_webview_window(crate::MAIN_WINDOW_LABEL) {
+        if let Err(e) = window.request_user_attention(Some(UserAttentionType::Informational)) {
+            log::error!("Failed to request attention: {}", e);
+        }
+    }
+    Ok(())
+}
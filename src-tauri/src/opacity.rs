@@ -0,0 +1,70 @@
+// Window opacity
+//
+// Tauri has no cross-platform opacity API, so this goes to the native window
+// directly. Supported on Windows (layered windows) and macOS (NSWindow alpha);
+// Linux and mobile return an error.
+
+use tauri::Window;
+
+/// Lowest alpha allowed, so a window can't be made fully invisible.
+const MIN_OPACITY: f64 = 0.2;
+
+#[tauri::command]
+pub fn set_window_opacity(window: Window, opacity: f64) -> Result<(), String> {
+    platform::set_opacity(&window, opacity.clamp(MIN_OPACITY, 1.0))
+}
+
+#[cfg(windows)]
+mod platform {
+    use tauri::Window;
+    use windows::Win32::Foundation::COLORREF;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SetLayeredWindowAttributes, GWL_EXSTYLE, LWA_ALPHA, WS_EX_LAYERED,
+    };
+    // The `LongPtr` variants only exist on 64-bit targets
+    #[cfg(target_pointer_width = "64")]
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindowLongPtrW as GetWindowLong, SetWindowLongPtrW as SetWindowLong,
+    };
+    #[cfg(target_pointer_width = "32")]
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindowLongW as GetWindowLong, SetWindowLongW as SetWindowLong,
+    };
+
+    pub fn set_opacity(window: &Window, opacity: f64) -> Result<(), String> {
+        let hwnd = window.hwnd().map_err(crate::window_error)?;
+        let alpha = (opacity * 255.0).round() as u8;
+
+        // SAFETY: `hwnd` is the live handle Tauri owns for this window
+        unsafe {
+            let style = GetWindowLong(hwnd, GWL_EXSTYLE);
+            SetWindowLong(hwnd, GWL_EXSTYLE, style | WS_EX_LAYERED.0 as _);
+            SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA)
+                .map_err(crate::window_error)
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2_app_kit::NSWindow;
+    use tauri::Window;
+
+    pub fn set_opacity(window: &Window, opacity: f64) -> Result<(), String> {
+        let ns_window = window.ns_window().map_err(crate::window_error)? as *const NSWindow;
+
+        // SAFETY: Tauri hands back the live NSWindow for this window, and
+        // synchronous commands run on the main thread as AppKit requires
+        unsafe { (*ns_window).setAlphaValue(opacity) };
+        Ok(())
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+mod platform {
+    use tauri::Window;
+
+    pub fn set_opacity(_window: &Window, _opacity: f64) -> Result<(), String> {
+        Err("window opacity is only supported on Windows and macOS".into())
+    }
+}
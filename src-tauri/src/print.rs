@@ -0,0 +1,99 @@
+// Print to PDF
+//
+// Only WebView2 (Windows) can write the current page straight to a PDF file.
+// WKWebView (macOS) and WebKitGTK (Linux) don't expose an equivalent through
+// Tauri, so there the command returns an error and the frontend should fall
+// back to the regular print dialog, which offers "Save as PDF" itself.
+
+use std::path::PathBuf;
+
+use tauri::WebviewWindow;
+
+#[tauri::command]
+pub async fn print_to_pdf(window: WebviewWindow, path: String) -> Result<(), String> {
+    let path = PathBuf::from(path);
+    let is_pdf = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"));
+    if !is_pdf {
+        return Err(format!("not a .pdf path: {}", path.display()));
+    }
+
+    platform::print_to_pdf(&window, path).await
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use tauri::WebviewWindow;
+    use tokio::sync::mpsc;
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2PrintSettings, ICoreWebView2_7,
+    };
+    use webview2_com::PrintToPdfCompletedHandler;
+    use windows::core::{Interface, HSTRING};
+
+    const PRINT_TIMEOUT: Duration = Duration::from_secs(60);
+
+    pub async fn print_to_pdf(window: &WebviewWindow, path: PathBuf) -> Result<(), String> {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Result<(), String>>();
+
+        let on_error = tx.clone();
+        window
+            .with_webview(move |webview| {
+                let path = HSTRING::from(path.as_os_str());
+                let handler = PrintToPdfCompletedHandler::create(Box::new(
+                    move |result: windows::core::Result<()>, success| {
+                        let outcome = match result {
+                            Err(e) => Err(e.to_string()),
+                            Ok(()) if !success.as_bool() => {
+                                Err("WebView2 failed to write the PDF".into())
+                            }
+                            Ok(()) => Ok(()),
+                        };
+                        let _ = tx.send(outcome);
+                        Ok(())
+                    },
+                ));
+
+                let started = unsafe {
+                    webview
+                        .controller()
+                        .CoreWebView2()
+                        .and_then(|core| core.cast::<ICoreWebView2_7>())
+                        .and_then(|core| {
+                            core.PrintToPdf(&path, None::<&ICoreWebView2PrintSettings>, &handler)
+                        })
+                };
+                if let Err(e) = started {
+                    let _ = on_error.send(Err(format!(
+                        "this WebView2 runtime can't print to PDF: {}",
+                        e
+                    )));
+                }
+            })
+            .map_err(crate::window_error)?;
+
+        match tokio::time::timeout(PRINT_TIMEOUT, rx.recv()).await {
+            Ok(Some(outcome)) => outcome,
+            Ok(None) => Err("print to PDF was aborted".into()),
+            Err(_) => Err("timed out waiting for the PDF to be written".into()),
+        }
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    use std::path::PathBuf;
+
+    use tauri::WebviewWindow;
+
+    pub async fn print_to_pdf(_window: &WebviewWindow, _path: PathBuf) -> Result<(), String> {
+        Err(
+            "this platform's webview can't save directly to PDF; use Print and choose \"Save as PDF\" instead"
+                .into(),
+        )
+    }
+}
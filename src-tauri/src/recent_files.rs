@@ -0,0 +1,70 @@
+// Recently used backup files
+//
+// Most recent first, capped at `MAX_RECENT_FILES`, stored as a JSON array in
+// the app config dir.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+
+const RECENT_FILES_FILE: &str = "recent-files.json";
+const MAX_RECENT_FILES: usize = 10;
+
+// Serializes read-modify-write cycles between concurrent commands
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn list_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    Ok(dir.join(RECENT_FILES_FILE))
+}
+
+fn load(app: &AppHandle) -> Vec<String> {
+    list_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn store(app: &AppHandle, files: &[String]) -> Result<(), String> {
+    let path = list_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(files).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Moves `path` to the top of the list, adding it if it isn't there yet.
+pub fn push(app: &AppHandle, path: &Path) -> Result<(), String> {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let path = path.display().to_string();
+    let mut files = load(app);
+    files.retain(|file| *file != path);
+    files.insert(0, path);
+    files.truncate(MAX_RECENT_FILES);
+    store(app, &files)
+}
+
+/// Returns the list, dropping entries whose files have since been removed.
+#[tauri::command]
+pub fn get_recent_files(app: AppHandle) -> Vec<String> {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let files = load(&app);
+    let existing: Vec<String> = files
+        .iter()
+        .filter(|file| Path::new(file).exists())
+        .cloned()
+        .collect();
+
+    if existing.len() != files.len() {
+        if let Err(e) = store(&app, &existing) {
+            log::error!("Failed to prune recent files: {}", e);
+        }
+    }
+    existing
+}
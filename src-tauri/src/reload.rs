@@ -0,0 +1,125 @@
+// Window reload
+//
+// A hard reload empties the webview's HTTP cache first, so neither the page
+// nor the scripts and styles it loads can come from a stale copy. Only the
+// cache is touched: `clear_all_browsing_data` would also wipe IndexedDB, which
+// is where the frontend keeps its data.
+//
+// WebView2 (Windows) and WebKitGTK (Linux) can clear just the cache. WKWebView
+// (macOS) only exposes that through its website data store, so there the page
+// is reloaded from origin instead, which revalidates every resource with the
+// server rather than trusting the cache.
+
+use tauri::WebviewWindow;
+
+#[tauri::command]
+pub async fn reload_window(window: WebviewWindow, hard: bool) -> Result<(), String> {
+    if hard {
+        platform::hard_reload(&window).await
+    } else {
+        window.reload().map_err(crate::window_error)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::time::Duration;
+
+    use tauri::WebviewWindow;
+    use tokio::sync::mpsc;
+    use webview2_com::ClearBrowsingDataCompletedHandler;
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2Profile2, ICoreWebView2_13, COREWEBVIEW2_BROWSING_DATA_KINDS_DISK_CACHE,
+    };
+    use windows::core::Interface;
+
+    const CLEAR_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub async fn hard_reload(window: &WebviewWindow) -> Result<(), String> {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Result<(), String>>();
+
+        let on_error = tx.clone();
+        window
+            .with_webview(move |webview| {
+                let started = unsafe {
+                    webview.controller().CoreWebView2().and_then(|core| {
+                        let profile = core
+                            .cast::<ICoreWebView2_13>()?
+                            .Profile()?
+                            .cast::<ICoreWebView2Profile2>()?;
+                        let handler = ClearBrowsingDataCompletedHandler::create(Box::new(
+                            move |result: windows::core::Result<()>| {
+                                let outcome = result
+                                    .and_then(|_| core.Reload())
+                                    .map_err(|e| e.to_string());
+                                let _ = tx.send(outcome);
+                                Ok(())
+                            },
+                        ));
+                        profile.ClearBrowsingDataAsync(
+                            COREWEBVIEW2_BROWSING_DATA_KINDS_DISK_CACHE,
+                            &handler,
+                        )
+                    })
+                };
+                if let Err(e) = started {
+                    let _ = on_error.send(Err(format!(
+                        "this WebView2 runtime can't clear its cache: {}",
+                        e
+                    )));
+                }
+            })
+            .map_err(crate::window_error)?;
+
+        match tokio::time::timeout(CLEAR_TIMEOUT, rx.recv()).await {
+            Ok(Some(outcome)) => outcome,
+            Ok(None) => Err("hard reload was aborted".into()),
+            Err(_) => Err("timed out waiting for the cache to clear".into()),
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2_web_kit::WKWebView;
+    use tauri::WebviewWindow;
+
+    pub async fn hard_reload(window: &WebviewWindow) -> Result<(), String> {
+        window
+            .with_webview(|webview| {
+                // SAFETY: Tauri hands back the live WKWebView for this window
+                // and runs this closure on the main thread
+                unsafe {
+                    let _ = (*webview.inner().cast::<WKWebView>()).reloadFromOrigin();
+                }
+            })
+            .map_err(crate::window_error)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use tauri::WebviewWindow;
+    use webkit2gtk::{WebContextExt, WebViewExt};
+
+    pub async fn hard_reload(window: &WebviewWindow) -> Result<(), String> {
+        window
+            .with_webview(|webview| {
+                let webview = webview.inner();
+                if let Some(context) = webview.context() {
+                    context.clear_cache();
+                }
+                webview.reload_bypass_cache();
+            })
+            .map_err(crate::window_error)
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+mod platform {
+    use tauri::WebviewWindow;
+
+    pub async fn hard_reload(_window: &WebviewWindow) -> Result<(), String> {
+        Err("this platform's webview can't clear its cache".into())
+    }
+}
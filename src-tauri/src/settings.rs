@@ -0,0 +1,79 @@
+// Native-side user settings
+//
+// Persisted as JSON in the app config dir and kept in managed state behind a
+// `Mutex` so commands and event handlers read the current values.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime, State};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Hide the last window to the tray instead of quitting when it is closed.
+    pub close_to_tray: bool,
+    /// Global shortcut that opens a new window, registered at startup.
+    pub new_window_shortcut: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            close_to_tray: false,
+            new_window_shortcut: "CmdOrCtrl+Shift+N".into(),
+        }
+    }
+}
+
+pub type SettingsState = Mutex<Settings>;
+
+fn settings_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    Ok(dir.join(SETTINGS_FILE))
+}
+
+/// Reads saved settings, falling back to defaults if the file is missing or unreadable.
+pub fn load<R: Runtime>(app: &AppHandle<R>) -> Settings {
+    settings_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save<R: Runtime>(app: &AppHandle<R>, settings: &Settings) -> Result<(), String> {
+    let path = settings_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Returns a snapshot of the current settings.
+pub fn current<R: Runtime>(app: &AppHandle<R>) -> Settings {
+    let state = app.state::<SettingsState>();
+    let settings = state.lock().unwrap_or_else(|e| e.into_inner());
+    settings.clone()
+}
+
+#[tauri::command]
+pub fn get_settings(state: State<'_, SettingsState>) -> Settings {
+    state.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    state: State<'_, SettingsState>,
+    settings: Settings,
+) -> Result<(), String> {
+    save(&app, &settings)?;
+    *state.lock().unwrap_or_else(|e| e.into_inner()) = settings;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// Global keyboard shortcuts
+//
+// Registered with the OS so they fire even when RichDad is unfocused or hidden
+// to the tray.
+
+use tauri::App;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+use crate::settings;
+
+pub fn init(app: &App) -> tauri::Result<()> {
+    app.handle().plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(|app, _shortcut, event| {
+                if event.state() == ShortcutState::Pressed {
+                    if let Err(e) = crate::open_new_window(app, None, None, None) {
+                        log::error!("Failed to open window from shortcut: {}", e);
+                    }
+                }
+            })
+            .build(),
+    )?;
+
+    // Another app may already own the combination; that shouldn't stop startup
+    let shortcut = settings::current(app.handle()).new_window_shortcut;
+    if let Err(e) = app.global_shortcut().register(shortcut.as_str()) {
+        log::warn!("Could not register global shortcut '{}': {}", shortcut, e);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Startup splash screen
+//
+// The main window starts hidden behind a small frameless splash while the
+// frontend bundle loads. The frontend calls `close_splashscreen` once it has
+// initialized; if it never does (e.g. it failed to load), the main window is
+// shown anyway after `SPLASH_TIMEOUT`.
+
+use std::time::Duration;
+
+use tauri::{App, AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+pub const SPLASH_LABEL: &str = "splashscreen";
+const SPLASH_TIMEOUT: Duration = Duration::from_secs(8);
+
+pub fn init(app: &App) -> tauri::Result<()> {
+    // A static page in `public/`, so it renders without booting the app bundle
+    let splash =
+        WebviewWindowBuilder::new(app, SPLASH_LABEL, WebviewUrl::App("splash.html".into()))
+            .title(crate::DEFAULT_TITLE)
+            .inner_size(400.0, 300.0)
+            .resizable(false)
+            .decorations(false)
+            .skip_taskbar(true)
+            .center()
+            .build()?;
+    crate::remove_menu_bar(&splash);
+
+    let handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(SPLASH_TIMEOUT).await;
+        if let Err(e) = close_splashscreen(handle).await {
+            log::error!("Failed to show main window after splash timeout: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+/// Closes the splash and shows the main window. Does nothing once the splash
+/// is gone, so windows opened later can call it freely.
+// Async for the same reason as `create_new_window`, since the main window may
+// have to be rebuilt
+#[tauri::command]
+pub async fn close_splashscreen(app: AppHandle) -> Result<(), String> {
+    let Some(splash) = app.get_webview_window(SPLASH_LABEL) else {
+        return Ok(());
+    };
+
+    // `destroy` rather than `close`, so the close confirmation and window
+    // state persistence don't kick in for the splash
+    splash.destroy().map_err(|e| e.to_string())?;
+    crate::show_main_window(&app)
+}
@@ -0,0 +1,58 @@
+// System tray icon
+//
+// Left-clicking the icon brings back the main window; the context menu offers
+// the same actions as the window commands so behaviour stays consistent.
+// Note that tray click events are not emitted on Linux, where the menu is the
+// only way to interact with the icon.
+
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{App, Manager};
+
+const TRAY_ID: &str = "richdad-tray";
+
+const MENU_SHOW: &str = "tray_show";
+const MENU_NEW_WINDOW: &str = "tray_new_window";
+const MENU_QUIT: &str = "tray_quit";
+
+pub fn init(app: &App) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, MENU_SHOW, "Show RichDad", true, None::<&str>)?;
+    let new_window = MenuItem::with_id(app, MENU_NEW_WINDOW, "New Window", true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let quit = MenuItem::with_id(app, MENU_QUIT, "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &new_window, &separator, &quit])?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("RichDad")
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            MENU_SHOW => report(crate::show_main_window(app)),
+            MENU_NEW_WINDOW => report(crate::open_new_window(app, None, None, None)),
+            MENU_QUIT => crate::quit_app(app.clone()),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                report(crate::show_main_window(tray.app_handle()));
+            }
+        });
+
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+
+    builder.build(app)?;
+    Ok(())
+}
+
+fn report(result: Result<(), String>) {
+    if let Err(e) = result {
+        log::error!("Tray action failed: {}", e);
+    }
+}
@@ -0,0 +1,89 @@
+// Auto-update
+//
+// Checked once in the background at startup so the window opens immediately.
+// When an update is found the frontend gets an `update-available` event and
+// calls `install_update` once the user confirms.
+//
+// Updates are signed, so both need the minisign public key in
+// `plugins.updater.pubkey` (and release builds need
+// `bundle.createUpdaterArtifacts`). Until a key is configured the check is
+// skipped with a warning rather than announcing updates that would fail
+// signature verification on install.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_updater::UpdaterExt;
+
+#[derive(Clone, Serialize)]
+struct UpdateAvailable {
+    version: String,
+    notes: Option<String>,
+}
+
+/// Whether a signing key and at least one endpoint are configured.
+fn is_configured(app: &AppHandle) -> bool {
+    let Some(config) = app.config().plugins.0.get("updater") else {
+        return false;
+    };
+    let has_pubkey = config
+        .get("pubkey")
+        .and_then(|pubkey| pubkey.as_str())
+        .is_some_and(|pubkey| !pubkey.trim().is_empty());
+    let has_endpoint = config
+        .get("endpoints")
+        .and_then(|endpoints| endpoints.as_array())
+        .is_some_and(|endpoints| !endpoints.is_empty());
+    has_pubkey && has_endpoint
+}
+
+pub fn check_in_background(app: &AppHandle) {
+    if !is_configured(app) {
+        log::warn!("No updater signing key or endpoint configured, skipping update check");
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let update = match app.updater() {
+            Ok(updater) => updater.check().await,
+            Err(e) => Err(e),
+        };
+
+        match update {
+            Ok(Some(update)) => {
+                let payload = UpdateAvailable {
+                    version: update.version.clone(),
+                    notes: update.body.clone(),
+                };
+                if let Err(e) = app.emit("update-available", payload) {
+                    log::error!("Failed to emit update-available: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => log::warn!("Update check failed: {}", e),
+        }
+    });
+}
+
+/// Downloads and installs the latest update, then relaunches the app.
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    if !is_configured(&app) {
+        return Err("updates are not configured for this build".into());
+    }
+
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("no update available")?;
+
+    update
+        .download_and_install(|_chunk, _total| {}, || {})
+        .await
+        .map_err(|e| e.to_string())?;
+
+    app.restart()
+}
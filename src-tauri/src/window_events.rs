@@ -0,0 +1,86 @@
+// Window events forwarded to the frontend
+//
+// The custom titlebar mirrors native window state, so changes made outside of
+// our commands (titlebar double-click, snap gestures) are pushed as events.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{Emitter, Theme, Window};
+
+const RESIZE_DEBOUNCE: Duration = Duration::from_millis(150);
+
+// Latest resize generation per window label; a pending emit only fires if no
+// newer resize arrived while it was waiting
+static RESIZE_GENERATIONS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+#[derive(Clone, Serialize)]
+struct WindowStateChanged {
+    label: String,
+    is_maximized: bool,
+    is_fullscreen: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct ThemeChanged {
+    label: String,
+    theme: &'static str,
+}
+
+pub fn theme_name(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Dark => "dark",
+        _ => "light",
+    }
+}
+
+/// Emits `theme-changed` when the OS appearance flips so charts re-theme live.
+pub fn on_theme_changed(window: &Window, theme: Theme) {
+    let payload = ThemeChanged {
+        label: window.label().to_string(),
+        theme: theme_name(theme),
+    };
+    if let Err(e) = window.emit("theme-changed", payload) {
+        log::error!("Failed to emit theme-changed: {}", e);
+    }
+}
+
+/// Emits `window-state-changed`. `is_maximized` is passed in because right
+/// after `maximize`/`unmaximize` GTK and macOS can still report the old state.
+pub fn emit_state_changed(window: &Window, is_maximized: bool) {
+    let payload = WindowStateChanged {
+        label: window.label().to_string(),
+        is_maximized,
+        is_fullscreen: window.is_fullscreen().unwrap_or(false),
+    };
+    if let Err(e) = window.emit("window-state-changed", payload) {
+        log::error!("Failed to emit window-state-changed: {}", e);
+    }
+}
+
+/// Emits `window-state-changed` once a drag-resize has settled.
+pub fn on_resized(window: &Window) {
+    let label = window.label().to_string();
+    let generation = {
+        let mut generations = RESIZE_GENERATIONS.lock().unwrap_or_else(|e| e.into_inner());
+        let generation = generations.entry(label.clone()).or_insert(0);
+        *generation = generation.wrapping_add(1);
+        *generation
+    };
+
+    let window = window.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(RESIZE_DEBOUNCE).await;
+
+        let latest = RESIZE_GENERATIONS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&label)
+            .copied();
+        if latest == Some(generation) {
+            emit_state_changed(&window, window.is_maximized().unwrap_or(false));
+        }
+    });
+}
@@ -0,0 +1,253 @@
+// Window state persistence
+//
+// Each window's outer position, inner size, maximized flag and zoom level are
+// saved to a JSON file in the app config dir when it closes, keyed by window
+// label, and applied back the next time a window with that label is created.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow, WebviewWindowBuilder, Window};
+
+const STATE_FILE: &str = "window-state.json";
+
+// Last bounds each window had while in its normal (not maximized, minimized or
+// fullscreen) state, so a maximized window is saved with the bounds it should
+// un-maximize back to rather than the monitor size
+static NORMAL_BOUNDS: Mutex<BTreeMap<String, WindowState>> = Mutex::new(BTreeMap::new());
+
+// Current webview zoom per window; the webview has no getter for it
+static ZOOM_LEVELS: Mutex<BTreeMap<String, f64>> = Mutex::new(BTreeMap::new());
+
+/// Saved bounds in logical pixels so they survive DPI changes between runs.
+///
+/// When `is_maximized` is set the bounds are the pre-maximize ones.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    #[serde(default)]
+    pub is_maximized: bool,
+    #[serde(default = "default_zoom")]
+    pub zoom: f64,
+}
+
+fn default_zoom() -> f64 {
+    1.0
+}
+
+fn state_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    Ok(dir.join(STATE_FILE))
+}
+
+fn load_all<R: Runtime>(app: &AppHandle<R>) -> HashMap<String, WindowState> {
+    state_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_all<R: Runtime>(
+    app: &AppHandle<R>,
+    states: &HashMap<String, WindowState>,
+) -> Result<(), String> {
+    let path = state_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(states).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+fn current_bounds<R: Runtime>(window: &Window<R>) -> Result<WindowState, String> {
+    let scale = window.scale_factor().map_err(|e| e.to_string())?;
+    let position = window
+        .outer_position()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale);
+    let size = window
+        .inner_size()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale);
+
+    Ok(WindowState {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        is_maximized: false,
+        zoom: default_zoom(),
+    })
+}
+
+fn is_normal<R: Runtime>(window: &Window<R>) -> bool {
+    !window.is_maximized().unwrap_or(true)
+        && !window.is_minimized().unwrap_or(true)
+        && !window.is_fullscreen().unwrap_or(true)
+}
+
+/// Remembers the bounds of `window` if it is in its normal state. Called on
+/// every move and resize.
+pub fn track<R: Runtime>(window: &Window<R>) {
+    if !is_normal(window) {
+        return;
+    }
+    if let Ok(bounds) = current_bounds(window) {
+        NORMAL_BOUNDS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(window.label().to_string(), bounds);
+    }
+}
+
+/// Records the current bounds of `window` under its label.
+pub fn save<R: Runtime>(window: &Window<R>) -> Result<(), String> {
+    // A minimized window reports a bogus off-screen position on Windows
+    if window.is_minimized().map_err(|e| e.to_string())? {
+        return Ok(());
+    }
+
+    let app = window.app_handle();
+    let label = window.label();
+    let mut states = load_all(app);
+
+    let state = if is_normal(window) {
+        current_bounds(window)?
+    } else {
+        let tracked = NORMAL_BOUNDS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(label)
+            .copied();
+        match tracked.or_else(|| states.get(label).copied()) {
+            Some(bounds) => bounds,
+            None => current_bounds(window)?,
+        }
+    };
+
+    states.insert(
+        label.to_string(),
+        WindowState {
+            is_maximized: window.is_maximized().map_err(|e| e.to_string())?,
+            zoom: zoom(app, label),
+            ..state
+        },
+    );
+    write_all(app, &states)
+}
+
+/// Records the bounds of every open window. Failures are logged so one window
+/// can't stop the rest from being saved.
+pub fn save_all<R: Runtime>(app: &AppHandle<R>) {
+    for window in app.webview_windows().values() {
+        if let Err(e) = save(&window.as_ref().window()) {
+            log::error!("Failed to save window state: {}", e);
+        }
+    }
+}
+
+/// Returns the saved bounds for `label`, if any.
+pub fn saved<R: Runtime>(app: &AppHandle<R>, label: &str) -> Option<WindowState> {
+    load_all(app).get(label).copied()
+}
+
+/// Applies the saved bounds for `label`, if any, to a window builder.
+///
+/// When the saved position no longer overlaps any connected monitor (e.g. the
+/// window was last on a display that has since been unplugged), the window is
+/// centered instead.
+pub fn restore<'a, R: Runtime, M: Manager<R>>(
+    builder: WebviewWindowBuilder<'a, R, M>,
+    app: &AppHandle<R>,
+    label: &str,
+) -> WebviewWindowBuilder<'a, R, M> {
+    let Some(state) = saved(app, label) else {
+        return builder;
+    };
+
+    let builder = builder.inner_size(state.width, state.height);
+    if is_on_screen(app, &state) {
+        builder.position(state.x, state.y)
+    } else {
+        builder.center()
+    }
+}
+
+/// Returns the saved position for `label` if it is still on a connected
+/// monitor.
+pub fn saved_position<R: Runtime>(app: &AppHandle<R>, label: &str) -> Option<(f64, f64)> {
+    saved(app, label)
+        .filter(|state| is_on_screen(app, state))
+        .map(|state| (state.x, state.y))
+}
+
+fn is_on_screen<R: Runtime>(app: &AppHandle<R>, state: &WindowState) -> bool {
+    let Ok(monitors) = app.available_monitors() else {
+        return false;
+    };
+
+    monitors.iter().any(|monitor| {
+        let scale = monitor.scale_factor();
+        let position = monitor.position().to_logical::<f64>(scale);
+        let size = monitor.size().to_logical::<f64>(scale);
+
+        state.x < position.x + size.width
+            && state.x + state.width > position.x
+            && state.y < position.y + size.height
+            && state.y + state.height > position.y
+    })
+}
+
+/// Returns the current zoom factor for `label`.
+pub fn zoom<R: Runtime>(app: &AppHandle<R>, label: &str) -> f64 {
+    let tracked = ZOOM_LEVELS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(label)
+        .copied();
+    tracked
+        .or_else(|| saved(app, label).map(|state| state.zoom))
+        .unwrap_or_else(default_zoom)
+}
+
+/// Remembers a window's zoom factor, writing it straight through to the saved
+/// state when the window already has an entry there.
+pub fn record_zoom<R: Runtime>(app: &AppHandle<R>, label: &str, factor: f64) -> Result<(), String> {
+    ZOOM_LEVELS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(label.to_string(), factor);
+
+    let mut states = load_all(app);
+    match states.get_mut(label) {
+        Some(state) => {
+            state.zoom = factor;
+            write_all(app, &states)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Applies the parts of the saved state that can only be set once a window
+/// exists. The builder has already applied the pre-maximize bounds, so
+/// un-maximizing a re-maximized window returns to them.
+pub fn restore_after_build<R: Runtime>(window: &WebviewWindow<R>) -> tauri::Result<()> {
+    let Some(state) = saved(window.app_handle(), window.label()) else {
+        return Ok(());
+    };
+
+    if state.is_maximized {
+        window.maximize()?;
+    }
+    if state.zoom != default_zoom() {
+        window.set_zoom(state.zoom)?;
+    }
+    Ok(())
+}
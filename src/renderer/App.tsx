@@ -14,6 +14,8 @@ const Settings = lazy(() => import('./pages/Settings/Settings').then(m => ({ def
 import { useSettingsStore } from './stores/settingsStore'
 import { useMarketStore } from './stores/marketStore'
 import { useDataHeartbeat } from './hooks/useDataHeartbeat'
+import { useCloseGuard } from './hooks/useCloseGuard'
+import { useDeepLinks } from './hooks/useDeepLinks'
 import { initializeDatabase, getSettings, migrateApiKeysToEncrypted } from './lib/db'
 import { OnboardingWizard } from './components/Onboarding/OnboardingWizard'
 import { FloatingHelp } from './components/Help/FloatingHelp'
@@ -70,7 +72,10 @@ export default function App() {
       // Initialize Ollama after DB (non-blocking)
       initializeOllama()
     }
-    init().catch(console.error)
+    init()
+      .catch(console.error)
+      // Hide the native splash screen and reveal the main window
+      .finally(() => invoke('close_splashscreen').catch(console.error))
   }, [loadUserWatchlist, loadSelectedMarket, initializeOllama])
 
   // Auto-dismiss Ollama overlay only on success
@@ -97,6 +102,12 @@ export default function App() {
   // Initialize data heartbeat service
   useDataHeartbeat()
 
+  // Open richdad:// links on the page they point to
+  useDeepLinks()
+
+  // Let native window closes go through once the frontend agrees
+  useCloseGuard()
+
   // Apply CVD mode on mount if saved
   useEffect(() => {
     if (cvdMode) {
@@ -0,0 +1,33 @@
+import { useEffect } from 'react'
+import { invoke, isTauri } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+
+/**
+ * Answers the native side's close confirmation. The backend holds every window
+ * close until this window calls `confirm_close` (or `cancel_close` to stay
+ * open); closing the last window sends `confirm-quit` instead, answered with
+ * `quit_app` (or `cancel_close`). Either request is acknowledged first, or the
+ * backend closes the window after a few seconds assuming the renderer has
+ * hung. Nothing holds unsaved state yet, so both are confirmed right away; an
+ * "unsaved changes" prompt belongs here, after the acknowledgement.
+ * This should be used once at the app root level
+ */
+export function useCloseGuard() {
+  useEffect(() => {
+    if (!isTauri()) return
+
+    const unlisteners = [
+      listen('close-requested', async () => {
+        await invoke('acknowledge_close').catch(console.error)
+        invoke('confirm_close').catch(console.error)
+      }),
+      listen('confirm-quit', async () => {
+        await invoke('acknowledge_close').catch(console.error)
+        invoke('quit_app').catch(console.error)
+      }),
+    ]
+    return () => {
+      unlisteners.forEach(unlisten => unlisten.then(fn => fn()))
+    }
+  }, [])
+}
@@ -0,0 +1,39 @@
+import { useEffect } from 'react'
+import { invoke, isTauri } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { useNavigationStore } from '../stores/navigationStore'
+import type { PageId } from '../types'
+
+const PAGES: PageId[] = ['dashboard', 'news', 'backtest', 'settings']
+
+/**
+ * Navigates to richdad:// links, both the one the app was launched with and
+ * any that arrive while it is running. Routes look like `/news/...`; the first
+ * segment picks the page, and unknown pages fall back to the dashboard.
+ * This should be used once at the app root level
+ */
+export function useDeepLinks() {
+  const setPage = useNavigationStore(state => state.setPage)
+
+  useEffect(() => {
+    if (!isTauri()) return
+
+    const open = (route: string) => {
+      const segment = route.replace(/^\//, '').split(/[/?]/)[0]
+      const page = PAGES.find(p => p === segment)
+      if (!page) {
+        console.warn(`Deep link to unknown page: ${route}`)
+      }
+      setPage(page ?? 'dashboard')
+    }
+
+    invoke<string | null>('take_pending_deep_link')
+      .then(route => route && open(route))
+      .catch(console.error)
+
+    const unlisten = listen<{ route: string }>('deep-link', event => open(event.payload.route))
+    return () => {
+      unlisten.then(fn => fn())
+    }
+  }, [setPage])
+}